@@ -1,22 +1,189 @@
 use macroquad::prelude::*;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::path::Path;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::time::Instant;
 use image::{DynamicImage, ImageFormat};
+use serde::Deserialize;
 
 const CURRENT_FORMAT: &str = "avif";
-const FRAMES_PER_SHEET: usize = 24;
-const FPS: f32 = 15.0;
-const FRAME_TIME: f32 = 1.0 / FPS;
-const ORIGINAL_WIDTH: f32 = 600.0;
-const ORIGINAL_HEIGHT: f32 = 250.0;
+const VIDEO_CATALOG_PATH: &str = "videos.json";
 const MAX_LOADS_PER_FRAME: usize = 1; // Limit background processing
+const SHEETS_BEHIND: usize = 1; // Keep this many sheets before the current one resident
+const SHEETS_AHEAD: usize = 3; // Prefetch this many sheets past the current one
+const PREFETCH_SHEETS: usize = 2; // Sheets to buffer before playback is allowed to start
+const SEEK_SECONDS: f32 = 5.0; // Seconds to jump on a Left/Right arrow press while playing
+const SCRUB_BAR_HEIGHT: f32 = 10.0;
+const LOADING_BAR_HEIGHT: f32 = 4.0;
+const OSD_DURATION_SECS: f32 = 3.0; // How long the OSD stays up after a state change
+const NUMBER_KEYS: [KeyCode; 10] = [
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Key0,
+];
+
+/// Drives how `update` advances `current_frame` relative to decode progress,
+/// so a slow decode can hold playback instead of silently skipping frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DecodingState {
+    /// Buffering before the first frame; set by `start_playback`, after which
+    /// `update` holds the clock and audio back until `PREFETCH_SHEETS` sheets
+    /// (or every sheet of a shorter video) are resident.
+    Prefetch,
+    /// Advancing `current_frame` from the wall clock as usual.
+    Normal,
+    /// The sheet needed for the expected frame isn't decoded yet; the clock
+    /// is held until it arrives. Audio is stopped on entry and is not
+    /// restarted when playback resumes.
+    Waiting,
+    /// Playback has reached the last frame.
+    End,
+}
+
+/// How the decoded frame is scaled and letterboxed onto the window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScaleMode {
+    /// Fit to the window, preserving aspect ratio (the original behavior).
+    Auto,
+    /// Multiply the video's native frame size by a fixed factor.
+    Times(f32),
+    /// Force an exact destination size, ignoring aspect ratio.
+    Fixed(u32, u32),
+    /// Like `Auto`, but snapped down to the largest whole-integer factor for
+    /// pixel-perfect output.
+    IntegerNearest,
+}
+
+impl ScaleMode {
+    /// Parses a CLI/config value: `"auto"`, `"integer"`, a factor like
+    /// `"2x"`, or an exact size like `"800x600"`.
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim().to_ascii_lowercase();
+        match s.as_str() {
+            "auto" => return Some(ScaleMode::Auto),
+            "integer" => return Some(ScaleMode::IntegerNearest),
+            _ => {}
+        }
+        if let Some(factor) = s.strip_suffix('x') {
+            return factor.parse::<f32>().ok().map(ScaleMode::Times);
+        }
+        if let Some((w, h)) = s.split_once('x') {
+            return Some(ScaleMode::Fixed(w.parse().ok()?, h.parse().ok()?));
+        }
+        None
+    }
+
+    /// Cycles through the modes reachable from the scale-mode key ('S'); `Fixed`
+    /// is only reachable via CLI/config since it needs two numbers.
+    fn next(self) -> Self {
+        match self {
+            ScaleMode::Auto => ScaleMode::IntegerNearest,
+            ScaleMode::IntegerNearest => ScaleMode::Times(2.0),
+            ScaleMode::Times(factor) if factor < 3.0 => ScaleMode::Times(factor + 1.0),
+            ScaleMode::Times(_) | ScaleMode::Fixed(..) => ScaleMode::Auto,
+        }
+    }
+
+    /// Destination size in pixels before letterboxing is applied.
+    fn dest_size(self, native_w: f32, native_h: f32, screen_w: f32, screen_h: f32) -> (f32, f32) {
+        match self {
+            ScaleMode::Auto => {
+                let scale = (screen_w / native_w).min(screen_h / native_h);
+                (native_w * scale, native_h * scale)
+            }
+            ScaleMode::IntegerNearest => {
+                let scale = (screen_w / native_w).min(screen_h / native_h).floor().max(1.0);
+                (native_w * scale, native_h * scale)
+            }
+            ScaleMode::Times(factor) => (native_w * factor, native_h * factor),
+            ScaleMode::Fixed(w, h) => (w as f32, h as f32),
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            ScaleMode::Auto => "Auto".to_string(),
+            ScaleMode::IntegerNearest => "Integer".to_string(),
+            ScaleMode::Times(factor) => format!("{:.1}x", factor),
+            ScaleMode::Fixed(w, h) => format!("{}x{}", w, h),
+        }
+    }
+}
+
+/// Formats a duration in seconds as `mm:ss` for the OSD.
+fn format_time(seconds: f32) -> String {
+    let total_secs = seconds.max(0.0) as u32;
+    format!("{:02}:{:02}", total_secs / 60, total_secs % 60)
+}
+
+/// One entry in the `videos.json` manifest.
+#[derive(Deserialize)]
+struct VideoManifestEntry {
+    name: String,
+    base_path: String,
+    fps: f32,
+    frames_per_sheet: usize,
+    sheet_columns: usize,
+    width: f32,
+    height: f32,
+}
 
 struct VideoMetadata {
     name: String,
     base_path: String,
+    fps: f32,
+    frames_per_sheet: usize,
+    sheet_columns: usize,
+    frame_width: f32,
+    frame_height: f32,
     total_frames: usize,
+    total_sheets: usize,
+}
+
+impl VideoMetadata {
+    fn frame_time(&self) -> f32 {
+        1.0 / self.fps
+    }
+}
+
+/// Loads the list of playable videos and their per-video geometry/timing
+/// from a JSON manifest, so adding a cutscene or changing a sheet layout
+/// doesn't require recompiling.
+struct VideoCatalog {
+    videos: Vec<VideoMetadata>,
+}
+
+impl VideoCatalog {
+    fn load(path: &str) -> Self {
+        let data = std::fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("failed to read video catalog {path}: {err}"));
+        let entries: Vec<VideoManifestEntry> =
+            serde_json::from_str(&data).expect("invalid video catalog JSON");
+
+        let videos = entries
+            .into_iter()
+            .map(|entry| VideoMetadata {
+                name: entry.name,
+                base_path: entry.base_path,
+                fps: entry.fps,
+                frames_per_sheet: entry.frames_per_sheet,
+                sheet_columns: entry.sheet_columns,
+                frame_width: entry.width,
+                frame_height: entry.height,
+                total_frames: entry.frames_per_sheet,
+                total_sheets: 1,
+            })
+            .collect();
+
+        Self { videos }
+    }
 }
 
 struct BackgroundLoader {
@@ -35,7 +202,7 @@ impl BackgroundLoader {
         std::thread::spawn(move || {
             let path = Path::new(&base_path)
                 .join(format!("sprite_sheet_{:03}.{}", sheet_index, CURRENT_FORMAT));
-            
+
             // Read and decode image in background
             if let Ok(data) = std::fs::read(&path) {
                 if let Ok(format) = ImageFormat::from_path(&path) {
@@ -51,7 +218,10 @@ impl BackgroundLoader {
 struct CutscenePlayer {
     videos: Vec<VideoMetadata>,
     current_video: Option<usize>,
-    sprite_sheets: HashMap<usize, VecDeque<Option<Texture2D>>>,
+    // Sheets resident in memory, keyed by video index, then sheet index. Only sheets inside the
+    // current prefetch window are kept; everything else is dropped so memory
+    // use stays O(window) instead of O(movie length).
+    sprite_sheets: HashMap<usize, BTreeMap<usize, Texture2D>>,
     background_loader: BackgroundLoader,
     audio: Option<macroquad::audio::Sound>,
     playback_start_time: Option<Instant>,
@@ -61,27 +231,18 @@ struct CutscenePlayer {
     loading_progress: f32,
     loading_start_time: Option<Instant>,
     loading_queue: VecDeque<(usize, usize)>,
+    load_in_flight: bool,
+    decoding_state: DecodingState,
+    paused: bool,
+    goto_queue: VecDeque<usize>,
+    scale_mode: ScaleMode,
+    osd_expire: Option<Instant>,
     show_menu: bool,
 }
 
 impl CutscenePlayer {
-    async fn new() -> Self {
-        let video_names = vec![
-            "c_berlin", "c_london", "c_paris", "c_rom", "c_utro 1", "c_utro 2", "intro1", "intro2",
-            "iq", "korkeken",
-        ];
-
-        let videos = video_names
-            .into_iter()
-            .map(|name| VideoMetadata {
-                name: name.to_string(),
-                base_path: format!(
-                    "sheet_generator/movies/{}/sprite_sheets/{}",
-                    name, CURRENT_FORMAT
-                ),
-                total_frames: 100 * FRAMES_PER_SHEET,
-            })
-            .collect();
+    async fn new(scale_mode: ScaleMode) -> Self {
+        let videos = VideoCatalog::load(VIDEO_CATALOG_PATH).videos;
 
         Self {
             videos,
@@ -96,6 +257,12 @@ impl CutscenePlayer {
             loading_progress: 0.0,
             loading_start_time: None,
             loading_queue: VecDeque::new(),
+            load_in_flight: false,
+            decoding_state: DecodingState::Normal,
+            paused: false,
+            goto_queue: VecDeque::new(),
+            scale_mode,
+            osd_expire: None,
             show_menu: true,
         }
     }
@@ -123,25 +290,29 @@ impl CutscenePlayer {
 
         let base_path = self.videos[index].base_path.clone();
         let name = self.videos[index].name.clone();
+        let frames_per_sheet = self.videos[index].frames_per_sheet;
 
         let total_sheets = self.count_sprite_sheets(&base_path).await;
-        self.videos[index].total_frames = total_sheets * FRAMES_PER_SHEET;
+        self.videos[index].total_frames = total_sheets * frames_per_sheet;
+        self.videos[index].total_sheets = total_sheets;
 
         // Initialize video's sprite sheets storage
-        self.sprite_sheets.insert(index, VecDeque::new());
+        self.sprite_sheets.insert(index, BTreeMap::new());
 
         // Load first sheet immediately for playback
         let first_sheet_path =
             Path::new(&base_path).join(format!("sprite_sheet_000.{}", CURRENT_FORMAT));
         if let Ok(texture) = load_texture(first_sheet_path.to_str().unwrap()).await {
             if let Some(sheets) = self.sprite_sheets.get_mut(&index) {
-                sheets.push_back(Some(texture));
+                sheets.insert(0, texture);
             }
         }
 
-        // Queue remaining sheets for background loading
+        // Queue only the sheets inside the initial prefetch window; the rest
+        // are enqueued on demand as playback advances past them.
         self.loading_queue.clear();
-        for sheet_index in 1..total_sheets {
+        let window_end = SHEETS_AHEAD.min(total_sheets.saturating_sub(1));
+        for sheet_index in 1..=window_end {
             self.loading_queue.push_back((index, sheet_index));
         }
 
@@ -171,18 +342,31 @@ impl CutscenePlayer {
             self.sprite_sheets.remove(&video_index);
         }
         self.loading_queue.clear();
+        self.load_in_flight = false;
     }
 
     fn start_next_background_load(&mut self) {
+        if self.load_in_flight {
+            return;
+        }
         if let Some((video_index, sheet_index)) = self.loading_queue.front() {
             let base_path = self.videos[*video_index].base_path.clone();
             self.background_loader
                 .start_loading(*video_index, base_path, *sheet_index);
+            self.load_in_flight = true;
         }
     }
 
     fn process_background_loads(&mut self) {
-        while let Ok((video_index, img, sheet_index)) = self.background_loader.receiver.try_recv() {
+        // Cap GPU uploads per frame so a burst of decoded images can't stall
+        // the render loop.
+        for _ in 0..MAX_LOADS_PER_FRAME {
+            let Ok((video_index, img, sheet_index)) = self.background_loader.receiver.try_recv()
+            else {
+                break;
+            };
+            self.load_in_flight = false;
+
             if let Some(sheets) = self.sprite_sheets.get_mut(&video_index) {
                 // Convert decoded image to RGBA
                 let rgba = img.to_rgba8();
@@ -191,12 +375,12 @@ impl CutscenePlayer {
 
                 // Just create texture from decoded data - this is fast
                 let texture = Texture2D::from_rgba8(width as u16, height as u16, &rgba);
-                sheets.push_back(Some(texture));
+                sheets.insert(sheet_index, texture);
 
                 if let Some(current_video) = self.current_video {
                     if current_video == video_index {
-                        let total_sheets = self.loading_queue.len() + sheets.len();
-                        self.loading_progress = sheets.len() as f32 / total_sheets as f32;
+                        let window_len = self.loading_queue.len() + sheets.len();
+                        self.loading_progress = sheets.len() as f32 / window_len as f32;
                     }
                 }
             }
@@ -210,12 +394,95 @@ impl CutscenePlayer {
         }
     }
 
+    /// Keep only the sheets within `[current_sheet - SHEETS_BEHIND, current_sheet +
+    /// SHEETS_AHEAD]` resident, evicting the rest, and enqueue whichever sheets
+    /// inside that window aren't loaded or already queued yet.
+    fn update_sheet_window(&mut self) {
+        let Some(video_index) = self.current_video else {
+            return;
+        };
+        let total_sheets = self.videos[video_index].total_sheets;
+        if total_sheets == 0 {
+            return;
+        }
+
+        let frames_per_sheet = self.videos[video_index].frames_per_sheet;
+        let current_sheet = (self.current_frame / frames_per_sheet).min(total_sheets - 1);
+        let window_start = current_sheet.saturating_sub(SHEETS_BEHIND);
+        let window_end = (current_sheet + SHEETS_AHEAD).min(total_sheets - 1);
+
+        if let Some(sheets) = self.sprite_sheets.get_mut(&video_index) {
+            sheets.retain(|sheet_index, _| (window_start..=window_end).contains(sheet_index));
+
+            for sheet_index in window_start..=window_end {
+                let loaded = sheets.contains_key(&sheet_index);
+                let queued = self
+                    .loading_queue
+                    .iter()
+                    .any(|(v, s)| *v == video_index && *s == sheet_index);
+                if !loaded && !queued {
+                    self.loading_queue.push_back((video_index, sheet_index));
+                }
+            }
+        }
+
+        self.loading_queue
+            .retain(|(v, s)| *v != video_index || (window_start..=window_end).contains(s));
+
+        self.start_next_background_load();
+    }
+
     async fn start_playback(&mut self) {
         if let Some(_) = self.current_video {
             self.current_frame = 0;
-            self.playback_start_time = Some(Instant::now());
+            self.playback_start_time = None;
+            self.decoding_state = DecodingState::Prefetch;
             self.show_menu = false;
+            self.is_playing = true;
+            self.show_osd();
+        }
+    }
 
+    /// Keep the OSD up for `OSD_DURATION_SECS` after a state change.
+    fn show_osd(&mut self) {
+        self.osd_expire =
+            Some(Instant::now() + std::time::Duration::from_secs_f32(OSD_DURATION_SECS));
+    }
+
+    fn stop(&mut self) {
+        if let Some(audio) = &self.audio {
+            macroquad::audio::stop_sound(audio);
+        }
+        self.is_playing = false;
+        self.current_frame = 0;
+        self.playback_start_time = None;
+        self.decoding_state = DecodingState::Normal;
+        self.paused = false;
+        self.goto_queue.clear();
+        self.show_menu = true;
+    }
+
+    fn sheet_is_resident(&self, video_index: usize, sheet_index: usize) -> bool {
+        self.sprite_sheets
+            .get(&video_index)
+            .map_or(false, |sheets| sheets.contains_key(&sheet_index))
+    }
+
+    /// Block in `Prefetch` until enough sheets are buffered, then start the
+    /// clock and audio together.
+    fn advance_prefetch(&mut self) {
+        let Some(video_index) = self.current_video else {
+            return;
+        };
+        let sheets_ready = self
+            .sprite_sheets
+            .get(&video_index)
+            .map_or(0, |sheets| sheets.len());
+        let total_sheets = self.videos[video_index].total_sheets;
+        let target = PREFETCH_SHEETS.min(total_sheets.max(1));
+
+        if sheets_ready >= target {
+            self.playback_start_time = Some(Instant::now());
             if let Some(audio) = &self.audio {
                 macroquad::audio::play_sound(
                     audio,
@@ -225,19 +492,67 @@ impl CutscenePlayer {
                     },
                 );
             }
+            self.decoding_state = DecodingState::Normal;
+        }
+    }
 
-            self.is_playing = true;
+    /// Advance `current_frame` from the wall clock, dropping into `Waiting`
+    /// if decode has fallen behind playback.
+    fn advance_normal(&mut self) {
+        let Some(start_time) = self.playback_start_time else {
+            return;
+        };
+        let Some(video_index) = self.current_video else {
+            return;
+        };
+
+        let elapsed = start_time.elapsed();
+        let video = &self.videos[video_index];
+        let expected_frame = (elapsed.as_secs_f32() / video.frame_time()).floor() as usize;
+        let total_frames = video.total_frames;
+        let frames_per_sheet = video.frames_per_sheet;
+
+        if expected_frame >= total_frames {
+            self.decoding_state = DecodingState::End;
+            self.stop();
+            return;
+        }
+
+        let needed_sheet = expected_frame / frames_per_sheet;
+        if self.sheet_is_resident(video_index, needed_sheet) {
+            self.current_frame = expected_frame;
+        } else {
+            // Decode hasn't caught up: hold the last good frame instead of
+            // skipping past it, and mute rather than let audio run ahead.
+            if let Some(audio) = &self.audio {
+                macroquad::audio::stop_sound(audio);
+            }
+            self.decoding_state = DecodingState::Waiting;
         }
     }
 
-    fn stop(&mut self) {
-        if let Some(audio) = &self.audio {
-            macroquad::audio::stop_sound(audio);
+    /// Sit on the current frame until the sheet it needs arrives, then
+    /// re-anchor the clock so playback resumes instead of jumping forward by
+    /// however long the stall lasted.
+    fn advance_waiting(&mut self) {
+        let Some(video_index) = self.current_video else {
+            return;
+        };
+        let video = &self.videos[video_index];
+        // After a stall the held frame's sheet is resident and the next
+        // frame's is the missing one; after a seek it is the held frame's own.
+        let next_frame = (self.current_frame + 1).min(video.total_frames.saturating_sub(1));
+        if self.sheet_is_resident(video_index, self.current_frame / video.frames_per_sheet)
+            && self.sheet_is_resident(video_index, next_frame / video.frames_per_sheet)
+        {
+            self.playback_start_time = Some(
+                Instant::now()
+                    - std::time::Duration::from_secs_f32(
+                        self.current_frame as f32 * video.frame_time(),
+                    ),
+            );
+            self.decoding_state = DecodingState::Normal;
         }
-        self.is_playing = false;
-        self.current_frame = 0;
-        self.playback_start_time = None;
-        self.show_menu = true;
     }
 
     async fn toggle(&mut self, video_index: usize) {
@@ -250,6 +565,135 @@ impl CutscenePlayer {
         }
     }
 
+    fn toggle_pause(&mut self) {
+        if !self.is_playing || self.decoding_state == DecodingState::Prefetch {
+            return;
+        }
+
+        self.paused = !self.paused;
+        if self.paused {
+            if let Some(audio) = &self.audio {
+                macroquad::audio::stop_sound(audio);
+            }
+        } else {
+            // Re-anchor the clock to the frame we froze on so resuming picks
+            // up from here instead of jumping ahead by the paused duration.
+            // macroquad's `Sound` can't be resumed from a position, so audio
+            // stays silent for the rest of this playback, same tradeoff as
+            // `advance_waiting`.
+            let video_index = self.current_video.expect("paused without a current video");
+            let frame_time = self.videos[video_index].frame_time();
+            self.playback_start_time = Some(
+                Instant::now()
+                    - std::time::Duration::from_secs_f32(self.current_frame as f32 * frame_time),
+            );
+            self.decoding_state = DecodingState::Normal;
+        }
+        self.show_osd();
+    }
+
+    fn cycle_scale_mode(&mut self) {
+        self.scale_mode = self.scale_mode.next();
+        self.show_osd();
+    }
+
+    /// Queue a relative frame step (positive or negative); drained by
+    /// `process_goto_queue` on the next `update`.
+    fn step_frame(&mut self, delta: i64) {
+        let Some(video_index) = self.current_video else {
+            return;
+        };
+        let total_frames = self.videos[video_index].total_frames;
+        let target = (self.current_frame as i64 + delta)
+            .clamp(0, total_frames.saturating_sub(1) as i64) as usize;
+        self.goto_queue.push_back(target);
+        self.show_osd();
+    }
+
+    fn seek_seconds(&mut self, delta_seconds: f32) {
+        let Some(video_index) = self.current_video else {
+            return;
+        };
+        let frame_time = self.videos[video_index].frame_time();
+        self.step_frame((delta_seconds / frame_time).round() as i64);
+    }
+
+    fn seek_to_frame(&mut self, frame: usize) {
+        self.goto_queue.push_back(frame);
+        self.show_osd();
+    }
+
+    /// Drain pending seeks, like Ruffle's `MovieClip::goto_queue`. Only the
+    /// most recent target matters, so a burst of scrub-drag events collapses
+    /// to a single jump instead of stepping through every intermediate
+    /// frame.
+    fn process_goto_queue(&mut self) {
+        let Some(target) = self.goto_queue.pop_back() else {
+            return;
+        };
+        self.goto_queue.clear();
+
+        let Some(video_index) = self.current_video else {
+            return;
+        };
+        let video = &self.videos[video_index];
+        self.current_frame = target.min(video.total_frames.saturating_sub(1));
+
+        let needed_sheet = self.current_frame / video.frames_per_sheet;
+        if self.sheet_is_resident(video_index, needed_sheet) {
+            if !self.paused {
+                let frame_time = video.frame_time();
+                self.playback_start_time = Some(
+                    Instant::now()
+                        - std::time::Duration::from_secs_f32(
+                            self.current_frame as f32 * frame_time,
+                        ),
+                );
+            }
+            self.decoding_state = DecodingState::Normal;
+        } else {
+            // The target sheet was evicted by the prefetch window; fall back
+            // to `Waiting` so `advance_waiting` re-anchors the clock once
+            // `update_sheet_window` pulls it back in.
+            if let Some(audio) = &self.audio {
+                macroquad::audio::stop_sound(audio);
+            }
+            self.decoding_state = DecodingState::Waiting;
+        }
+    }
+
+    fn scrub_bar_rect(&self) -> Rect {
+        Rect::new(
+            0.0,
+            screen_height() - SCRUB_BAR_HEIGHT,
+            screen_width(),
+            SCRUB_BAR_HEIGHT,
+        )
+    }
+
+    /// Map a click or drag on the bottom scrub bar to a target frame.
+    fn handle_scrub_input(&mut self) {
+        if self.show_menu || !self.is_playing {
+            return;
+        }
+        let Some(video_index) = self.current_video else {
+            return;
+        };
+        if !is_mouse_button_down(MouseButton::Left) {
+            return;
+        }
+
+        let bar = self.scrub_bar_rect();
+        let (mouse_x, mouse_y) = mouse_position();
+        if !bar.contains(vec2(mouse_x, mouse_y)) {
+            return;
+        }
+
+        let total_frames = self.videos[video_index].total_frames;
+        let fraction = ((mouse_x - bar.x) / bar.w).clamp(0.0, 1.0);
+        self.seek_to_frame((fraction * total_frames as f32) as usize);
+    }
+
     fn draw(&self) {
         clear_background(BLACK);
 
@@ -260,27 +704,31 @@ impl CutscenePlayer {
 
         if self.is_playing {
             if let Some(video_index) = self.current_video {
-                let sheet_index = self.current_frame / FRAMES_PER_SHEET;
-                let frame_in_sheet = self.current_frame % FRAMES_PER_SHEET;
-                let row = frame_in_sheet / 3;
-                let col = frame_in_sheet % 3;
+                let video = &self.videos[video_index];
+                let sheet_index = self.current_frame / video.frames_per_sheet;
+                let frame_in_sheet = self.current_frame % video.frames_per_sheet;
+                let row = frame_in_sheet / video.sheet_columns;
+                let col = frame_in_sheet % video.sheet_columns;
 
                 let src_rect = Rect::new(
-                    col as f32 * ORIGINAL_WIDTH,
-                    row as f32 * ORIGINAL_HEIGHT,
-                    ORIGINAL_WIDTH,
-                    ORIGINAL_HEIGHT,
+                    col as f32 * video.frame_width,
+                    row as f32 * video.frame_height,
+                    video.frame_width,
+                    video.frame_height,
                 );
 
                 let (screen_w, screen_h) = (screen_width(), screen_height());
-                let scale = (screen_w / ORIGINAL_WIDTH).min(screen_h / ORIGINAL_HEIGHT);
-                let scaled_w = ORIGINAL_WIDTH * scale;
-                let scaled_h = ORIGINAL_HEIGHT * scale;
+                let (scaled_w, scaled_h) = self.scale_mode.dest_size(
+                    video.frame_width,
+                    video.frame_height,
+                    screen_w,
+                    screen_h,
+                );
                 let x = (screen_w - scaled_w) / 2.0;
                 let y = (screen_h - scaled_h) / 2.0;
 
                 if let Some(sheets) = self.sprite_sheets.get(&video_index) {
-                    if let Some(Some(texture)) = sheets.get(sheet_index) {
+                    if let Some(texture) = sheets.get(&sheet_index) {
                         draw_texture_ex(
                             texture,
                             x,
@@ -295,6 +743,9 @@ impl CutscenePlayer {
                     }
                 }
 
+                self.draw_scrub_bar(video_index);
+                self.draw_osd(video_index);
+
                 // Draw loading progress if still loading sheets
                 if !self.loading_queue.is_empty() {
                     self.draw_loading_progress();
@@ -316,7 +767,8 @@ impl CutscenePlayer {
             draw_text(&text, x, y, font_size, WHITE);
         }
 
-        let instructions = "Press a number key to play/stop a video. Press 'Q' to quit.";
+        let instructions = "Number keys: play/stop. Space: pause. Left/Right: seek/step. \
+            'S': scale mode. 'O': show OSD. 'Q': quit.";
         let instructions_dims = measure_text(instructions, None, font_size as u16, 1.0);
         let instructions_x = (screen_width() - instructions_dims.width) / 2.0;
         let instructions_y = start_y + (self.videos.len() as f32 + 1.0) * line_height;
@@ -330,67 +782,151 @@ impl CutscenePlayer {
     }
 
     fn draw_loading_progress(&self) {
-        let progress_height = 4.0;
         let progress_width = screen_width();
-        let y = screen_height() - progress_height;
+        let y = screen_height() - SCRUB_BAR_HEIGHT - LOADING_BAR_HEIGHT;
 
         // Background
-        draw_rectangle(0.0, y, progress_width, progress_height, GRAY);
+        draw_rectangle(0.0, y, progress_width, LOADING_BAR_HEIGHT, GRAY);
 
         // Progress bar
-        if let Some(video_index) = self.current_video {
-            if let Some(sheets) = self.sprite_sheets.get(&video_index) {
-                let total_sheets = self.loading_queue.len() + sheets.len();
-                let progress = sheets.len() as f32 / total_sheets as f32;
-                draw_rectangle(0.0, y, progress_width * progress, progress_height, GREEN);
+        draw_rectangle(
+            0.0,
+            y,
+            progress_width * self.loading_progress.clamp(0.0, 1.0),
+            LOADING_BAR_HEIGHT,
+            GREEN,
+        );
+    }
+
+    fn draw_scrub_bar(&self, video_index: usize) {
+        let bar = self.scrub_bar_rect();
+        draw_rectangle(bar.x, bar.y, bar.w, bar.h, DARKGRAY);
+
+        let total_frames = self.videos[video_index].total_frames.max(1);
+        let progress = self.current_frame as f32 / total_frames as f32;
+        draw_rectangle(bar.x, bar.y, bar.w * progress, bar.h, BLUE);
+    }
+
+    /// Transient overlay with the video name, frame/time counters, playback
+    /// state, and scale mode; shown for `OSD_DURATION_SECS` after `show_osd`.
+    fn draw_osd(&self, video_index: usize) {
+        let Some(expire) = self.osd_expire else {
+            return;
+        };
+        if Instant::now() >= expire {
+            return;
+        }
+
+        let video = &self.videos[video_index];
+        let state_label = if self.paused {
+            "Paused"
+        } else {
+            match self.decoding_state {
+                DecodingState::Prefetch | DecodingState::Waiting => "Buffering",
+                DecodingState::Normal => "Playing",
+                DecodingState::End => "Stopped",
             }
+        };
+
+        let lines = [
+            video.name.clone(),
+            format!("Frame {}/{}", self.current_frame, video.total_frames),
+            format!(
+                "{} / {}",
+                format_time(self.current_frame as f32 * video.frame_time()),
+                format_time(video.total_frames as f32 * video.frame_time()),
+            ),
+            state_label.to_string(),
+            format!("Scale: {}", self.scale_mode.label()),
+        ];
+
+        let font_size = 18.0;
+        let padding = 8.0;
+        let line_height = font_size * 1.3;
+        let box_x = 12.0;
+        let box_y = 12.0;
+        let box_w = 220.0;
+        let box_h = padding * 2.0 + line_height * lines.len() as f32;
+
+        draw_rectangle(box_x, box_y, box_w, box_h, Color::new(0.0, 0.0, 0.0, 0.6));
+        for (i, line) in lines.iter().enumerate() {
+            draw_text(
+                line,
+                box_x + padding,
+                box_y + padding + line_height * (i as f32 + 1.0) - 4.0,
+                font_size,
+                WHITE,
+            );
         }
     }
 
     async fn update(&mut self) {
         self.process_background_loads();
-
-        if self.is_playing {
-            if let Some(start_time) = self.playback_start_time {
-                let elapsed = start_time.elapsed();
-                let expected_frame = (elapsed.as_secs_f32() / FRAME_TIME).floor() as usize;
-
-                if let Some(video_index) = self.current_video {
-                    let total_frames = self.videos[video_index].total_frames;
-                    if expected_frame >= total_frames {
-                        self.stop();
-                    } else {
-                        self.current_frame = expected_frame;
-                    }
-                }
+        self.process_goto_queue();
+
+        if self.is_playing && !self.paused {
+            match self.decoding_state {
+                DecodingState::Prefetch => self.advance_prefetch(),
+                DecodingState::Normal => self.advance_normal(),
+                DecodingState::Waiting => self.advance_waiting(),
+                DecodingState::End => {}
             }
         }
+
+        self.update_sheet_window();
     }
 }
 
 #[macroquad::main("Multi-Video Cutscene Player")]
 async fn main() {
-    let mut player = CutscenePlayer::new().await;
+    let initial_scale_mode = std::env::args()
+        .nth(1)
+        .and_then(|arg| ScaleMode::parse(&arg))
+        .unwrap_or(ScaleMode::Auto);
+    let mut player = CutscenePlayer::new(initial_scale_mode).await;
 
     loop {
         if !player.loading {
-            match get_last_key_pressed() {
-                Some(KeyCode::Q) => break,
-                Some(key) => match key {
-                    KeyCode::Key1 => player.toggle(1).await,
-                    KeyCode::Key2 => player.toggle(2).await,
-                    KeyCode::Key3 => player.toggle(3).await,
-                    KeyCode::Key4 => player.toggle(4).await,
-                    KeyCode::Key5 => player.toggle(5).await,
-                    KeyCode::Key6 => player.toggle(6).await,
-                    KeyCode::Key7 => player.toggle(7).await,
-                    KeyCode::Key8 => player.toggle(8).await,
-                    KeyCode::Key9 => player.toggle(9).await,
-                    KeyCode::Key0 => player.toggle(10).await,
-                    _ => (),
-                },
-                None => (),
+            if is_key_pressed(KeyCode::Q) {
+                break;
+            }
+
+            // Drive the play bindings from however many videos the catalog
+            // loaded, rather than a fixed 10-entry match; only the first ten
+            // entries get a number key.
+            for (key_index, key) in NUMBER_KEYS.iter().enumerate() {
+                if key_index >= player.videos.len() {
+                    break;
+                }
+                if is_key_pressed(*key) {
+                    player.toggle(key_index + 1).await;
+                }
+            }
+
+            if is_key_pressed(KeyCode::Space) {
+                player.toggle_pause();
+            }
+            if is_key_pressed(KeyCode::S) {
+                player.cycle_scale_mode();
+            }
+            if is_key_pressed(KeyCode::O) {
+                player.show_osd();
+            }
+            if is_key_pressed(KeyCode::Left) {
+                if player.paused {
+                    player.step_frame(-1);
+                } else {
+                    player.seek_seconds(-SEEK_SECONDS);
+                }
+            }
+            if is_key_pressed(KeyCode::Right) {
+                if player.paused {
+                    player.step_frame(1);
+                } else {
+                    player.seek_seconds(SEEK_SECONDS);
+                }
             }
+            player.handle_scrub_input();
         }
 
         player.update().await;